@@ -2,11 +2,22 @@ use std::env;
 use std::fmt;
 use std::error::Error;
 use std::fs::{self, File};
-use std::path::Path;
+use std::path::PathBuf;
 use std::io::Write;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-const URL: &str = "https://www.gotransit.com/en/trip-planning/seeschedules/full-schedules";
-const TEMP_SUBDIR_NAME: &str = "sched";
+use chrono::Utc;
+use clap::{Parser, Subcommand};
+use cron::Schedule;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+
+const DEFAULT_BASE_URL: &str = "https://www.gotransit.com/en/trip-planning/seeschedules/full-schedules";
+const CACHE_SUBDIR_NAME: &str = "sched";
+const DEFAULT_CACHE_TTL_HOURS: u64 = 12;
 
 #[derive(Debug, Clone)]
 struct ParseError;
@@ -31,79 +42,194 @@ impl fmt::Debug for ScheduleNotFoundError {
 }
 impl Error for ScheduleNotFoundError {}
 
-struct TempFile { filename: Box<Path> }
-impl TempFile {
-    fn get(name: &str) -> Self {
-        let mut pathbuf = env::temp_dir();
-        pathbuf.push(TEMP_SUBDIR_NAME);
-        fs::create_dir_all(&pathbuf).unwrap_or_default();
+#[derive(Serialize, Deserialize)]
+struct CacheMeta {
+    fetched_at: u64,
+}
+
+/// A persistent, on-disk home for one line's cached schedule: the downloaded
+/// PDF plus a metadata file recording when it was fetched. Unlike the old
+/// `TempFile`, nothing here gets deleted on drop.
+struct CacheEntry {
+    dir: PathBuf,
+}
 
-        pathbuf.push(name);
-        TempFile { filename: pathbuf.into_boxed_path() }
+impl CacheEntry {
+    fn for_line(name: &str) -> Result<Self, std::io::Error> {
+        let mut dir = cache_root();
+        dir.push(name);
+        fs::create_dir_all(&dir)?;
+        Ok(CacheEntry { dir })
     }
-    fn create(&self) -> Result<File, std::io::Error> {
-        File::create(&self.filename)
+
+    fn pdf_path(&self) -> PathBuf {
+        self.dir.join("schedule.pdf")
     }
-}
-impl Drop for TempFile {
-    fn drop(&mut self) {
-        fs::remove_file(&self.filename).unwrap_or_default();
+
+    fn meta_path(&self) -> PathBuf {
+        self.dir.join("meta.json")
+    }
+
+    fn create_pdf_file(&self) -> Result<File, std::io::Error> {
+        File::create(self.pdf_path())
+    }
+
+    fn read_meta(&self) -> Option<CacheMeta> {
+        let raw = fs::read_to_string(self.meta_path()).ok()?;
+        serde_json::from_str(&raw).ok()
+    }
+
+    /// How long ago this entry was last refreshed, if it has ever been fetched.
+    fn age(&self) -> Option<Duration> {
+        let meta = self.read_meta()?;
+        let fetched_at = UNIX_EPOCH + Duration::from_secs(meta.fetched_at);
+        SystemTime::now().duration_since(fetched_at).ok()
+    }
+
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        self.pdf_path().exists() && self.age().map_or(false, |age| age < ttl)
+    }
+
+    fn touch(&self) -> Result<(), Box<dyn Error>> {
+        let meta = CacheMeta {
+            fetched_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+        fs::write(self.meta_path(), serde_json::to_string(&meta)?)?;
+        Ok(())
     }
 }
 
-fn get_normalized_name(name: &str) -> String {
-    let lower_name = name.to_ascii_lowercase();
-    match lower_name.as_ref() {
+fn cache_root() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(env::temp_dir)
+        .join(CACHE_SUBDIR_NAME)
+}
+
+/// Looks up `lower_name` (already lowercased) as a known full/short/combo
+/// name, returning the canonical route code. Returns `None` when `lower_name`
+/// isn't recognized, as opposed to `get_normalized_name`, which falls back to
+/// echoing the input so it can be used as a best-effort cache key.
+fn lookup_code(lower_name: &str) -> Option<&'static str> {
+    match lower_name {
         // full names
-        "lakeshore west" => "01-18",
-        "milton" => "21",
-        "kitchener" => "30-31-33",
-        "barrie" => "63-65-68",
-        "richmond hill" => "61",
-        "stouffville" => "70-71",
-        "lakeshore east" => "09-90",
+        "lakeshore west" => Some("01-18"),
+        "milton" => Some("21"),
+        "kitchener" => Some("30-31-33"),
+        "barrie" => Some("63-65-68"),
+        "richmond hill" => Some("61"),
+        "stouffville" => Some("70-71"),
+        "lakeshore east" => Some("09-90"),
         // short names
-        "lw" => "01-18",
-        "mi" => "21",
-        "ki" => "30-31-33",
-        "ba" => "63-65-68",
-        "rh" => "61",
-        "st" => "70-71",
-        "le" => "09-90",
+        "lw" => Some("01-18"),
+        "mi" => Some("21"),
+        "ki" => Some("30-31-33"),
+        "ba" => Some("63-65-68"),
+        "rh" => Some("61"),
+        "st" => Some("70-71"),
+        "le" => Some("09-90"),
         // combos
-        "1" => "01-18",
-        "01" => "01-18",
-        "18" => "01-18",
-        "30" => "30-31-33",
-        "31" => "30-31-33",
-        "33" => "30-31-33",
-        "63" => "63-65-68",
-        "65" => "63-65-68",
-        "68" => "63-65-68",
-        "70" => "70-71",
-        "71" => "70-71",
-        "9" => "09-90",
-        "09" => "09-90",
-        "90" => "09-90",
-        "41" => "41-45-47-48",
-        "45" => "41-45-47-48",
-        "47" => "41-45-47-48",
-        "48" => "41-45-47-48",
-        "52" => "52-54-56",
-        "54" => "52-54-56",
-        "56" => "52-54-56",
-        // else
-        other => other,
-    }.to_string()
-}
-
-async fn download_full_schedules_page() -> Result<String, Box<dyn Error>> {
-    let resp = reqwest::get(URL).await?;
+        "1" => Some("01-18"),
+        "01" => Some("01-18"),
+        "18" => Some("01-18"),
+        "30" => Some("30-31-33"),
+        "31" => Some("30-31-33"),
+        "33" => Some("30-31-33"),
+        "63" => Some("63-65-68"),
+        "65" => Some("63-65-68"),
+        "68" => Some("63-65-68"),
+        "70" => Some("70-71"),
+        "71" => Some("70-71"),
+        "9" => Some("09-90"),
+        "09" => Some("09-90"),
+        "90" => Some("09-90"),
+        "41" => Some("41-45-47-48"),
+        "45" => Some("41-45-47-48"),
+        "47" => Some("41-45-47-48"),
+        "48" => Some("41-45-47-48"),
+        "52" => Some("52-54-56"),
+        "54" => Some("52-54-56"),
+        "56" => Some("52-54-56"),
+        _ => None,
+    }
+}
+
+fn get_normalized_name(name: &str) -> String {
+    let lower_name = name.to_ascii_lowercase();
+    lookup_code(&lower_name).map(str::to_string).unwrap_or(lower_name)
+}
+
+const RETRY_MAX_ATTEMPTS: u32 = 5;
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+}
+
+/// Delay after the given failed attempt (1-indexed), before the next one:
+/// starts at `RETRY_BASE_DELAY` and doubles each time, capped at
+/// `RETRY_MAX_DELAY`, with up to 25% jitter added to avoid thundering-herd
+/// retries against the same server.
+fn backoff_delay(attempt: u32) -> Duration {
+    let exponential = RETRY_BASE_DELAY.saturating_mul(1u32 << (attempt - 1).min(16));
+    let capped = exponential.min(RETRY_MAX_DELAY);
+    let jitter = rand::thread_rng().gen_range(0..=capped.as_millis() as u64 / 4);
+    capped + Duration::from_millis(jitter)
+}
+
+/// GETs `url`, retrying with exponential backoff on connection errors,
+/// timeouts, and retryable status codes (429/500/502/503/504). Fails fast on
+/// everything else (e.g. 404).
+async fn fetch_with_retry(
+    client: &reqwest::Client,
+    url: reqwest::Url,
+) -> Result<reqwest::Response, Box<dyn Error>> {
+    for attempt in 1..=RETRY_MAX_ATTEMPTS {
+        match client.get(url.clone()).send().await {
+            Ok(resp) => {
+                let status = resp.status();
+                if status.is_success() {
+                    return Ok(resp);
+                }
+                if attempt == RETRY_MAX_ATTEMPTS || !is_retryable_status(status) {
+                    return Err(Box::new(resp.error_for_status().unwrap_err()));
+                }
+            }
+            Err(e) => {
+                if attempt == RETRY_MAX_ATTEMPTS || !(e.is_timeout() || e.is_connect()) {
+                    return Err(Box::new(e));
+                }
+            }
+        }
+        tokio::time::sleep(backoff_delay(attempt)).await;
+    }
+    unreachable!("loop always returns on its last attempt")
+}
+
+async fn download_full_schedules_page(
+    client: &reqwest::Client,
+    base_url: &str,
+) -> Result<String, Box<dyn Error>> {
+    let resp = fetch_with_retry(client, reqwest::Url::parse(base_url)?).await?;
     Ok(resp.text().await?)
 }
 
-async fn find_pdf_link(name: &str) -> Result<String, Box<dyn Error>> {
-    let raw_html = download_full_schedules_page().await?;
+/// One row of the full-schedules table: the bolded key (e.g. "Lakeshore
+/// West"), the link text, and the href of the PDF it points to.
+struct ScheduleLink {
+    key: String,
+    link_text: String,
+    href: String,
+}
+
+/// Downloads the full-schedules page and parses its link table exactly once,
+/// so `get`/`refresh`/`list` can all share a single pass over it instead of
+/// re-downloading the page per command.
+async fn fetch_schedule_table(
+    client: &reqwest::Client,
+    base_url: &str,
+) -> Result<Vec<ScheduleLink>, Box<dyn Error>> {
+    let raw_html = download_full_schedules_page(client, base_url).await?;
     let document = scraper::Html::parse_document(&raw_html);
     let tbody_selector = scraper::Selector::parse("table[class='content-page-table']>tbody")?;
     let tbody = document.select(&tbody_selector).next().ok_or(ParseError)?;
@@ -112,59 +238,313 @@ async fn find_pdf_link(name: &str) -> Result<String, Box<dyn Error>> {
     let key_selector = scraper::Selector::parse("strong")?;
     let link_selector = scraper::Selector::parse("a")?;
 
+    let mut links = Vec::new();
     for tr in tbody.select(&tr_selector) {
         let key = tr.select(&key_selector).next().ok_or(ParseError)?;
         let link = tr.select(&link_selector).next().ok_or(ParseError)?;
+        let href = link.value().attr("href").ok_or(ParseError)?;
 
-        if key.inner_html().to_ascii_lowercase() == name
-            || link.inner_html().to_ascii_lowercase() == name {
-            return Ok(link.value().attr("href")
-                .ok_or(ParseError)?
-                .to_string());
-        }
+        links.push(ScheduleLink {
+            key: key.inner_html(),
+            link_text: link.inner_html(),
+            href: href.to_string(),
+        });
     }
 
-    Err(Box::new(ScheduleNotFoundError { name: name.to_string() }))
+    Ok(links)
+}
+
+fn find_pdf_link_in_table(table: &[ScheduleLink], name: &str) -> Result<String, Box<dyn Error>> {
+    table.iter()
+        .find(|link| link.key.to_ascii_lowercase() == name
+            || link.link_text.to_ascii_lowercase() == name)
+        .map(|link| link.href.clone())
+        .ok_or_else(|| Box::new(ScheduleNotFoundError { name: name.to_string() }) as Box<dyn Error>)
 }
 
 async fn download_pdf(
+    client: &reqwest::Client,
     url: reqwest::Url,
-    temp_file: &TempFile,
+    entry: &CacheEntry,
 ) -> Result<(), Box<dyn Error>> {
-    let response = reqwest::get(url).await?;
-    let mut file = temp_file.create()?;
+    let response = fetch_with_retry(client, url).await?;
+    let mut file = entry.create_pdf_file()?;
     let content = response.bytes().await?;
     file.write_all(&content)?;
     Ok(())
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        println!("Usage: sched <name>");
-        return Ok(());
+#[derive(Parser)]
+#[command(name = "sched", about = "Look up GO Transit schedule PDFs")]
+struct Cli {
+    /// Override the full-schedules URL (env: SCHED_BASE_URL)
+    #[arg(long, global = true)]
+    url: Option<String>,
+
+    /// Disable TLS certificate verification, e.g. when debugging behind an
+    /// intercepting proxy (env: SCHED_INSECURE)
+    #[arg(long, global = true)]
+    insecure: bool,
+
+    /// How many hours a cached schedule stays fresh before `get` re-fetches
+    /// it (default: 12; env: SCHED_CACHE_TTL)
+    #[arg(long, global = true)]
+    ttl: Option<u64>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Resolves the base URL from `--url`, falling back to `SCHED_BASE_URL`, then
+/// the real GO Transit site.
+fn resolve_base_url(cli_url: Option<String>) -> String {
+    cli_url
+        .or_else(|| env::var("SCHED_BASE_URL").ok())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+/// Resolves whether to skip TLS verification from `--insecure` or
+/// `SCHED_INSECURE`. The env var's *value* is parsed as a bool, so
+/// `SCHED_INSECURE=0`/`false`/empty is treated as off rather than merely
+/// being unset.
+fn resolve_insecure(cli_insecure: bool) -> bool {
+    let env_insecure = env::var("SCHED_INSECURE")
+        .ok()
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    cli_insecure || env_insecure
+}
+
+/// Resolves the cache TTL from `--ttl`, falling back to `SCHED_CACHE_TTL`,
+/// then `DEFAULT_CACHE_TTL_HOURS`. All three are in whole hours.
+fn resolve_cache_ttl(cli_ttl: Option<u64>) -> Duration {
+    let hours = cli_ttl
+        .or_else(|| env::var("SCHED_CACHE_TTL").ok().and_then(|v| v.trim().parse().ok()))
+        .unwrap_or(DEFAULT_CACHE_TTL_HOURS);
+    Duration::from_secs(hours * 60 * 60)
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Open the schedule PDF for one or more lines, reusing the cache where fresh
+    Get {
+        /// One or more lines, as name/short code/route number (quote multi-word
+        /// names, e.g. `sched get "lakeshore west" milton barrie`)
+        #[arg(required = true)]
+        names: Vec<String>,
+    },
+    /// List every line on the full-schedules page, with its resolved code
+    List,
+    /// Force re-download of one or more lines' schedules, bypassing the cache
+    Refresh {
+        /// One or more lines, as name/short code/route number (quote multi-word
+        /// names, e.g. `sched refresh "lakeshore west" milton barrie`)
+        #[arg(required = true)]
+        names: Vec<String>,
+    },
+    /// Keep one or more lines' cached schedules fresh on a cron schedule,
+    /// without opening a PDF viewer
+    Watch {
+        /// One or more lines, as name/short code/route number (quote multi-word
+        /// names, e.g. `sched watch "lakeshore west" milton barrie`)
+        #[arg(required = true)]
+        names: Vec<String>,
+        /// 5-field cron expression (minute hour day-of-month month
+        /// day-of-week), evaluated in UTC, e.g. "0 4 * * *" for daily at 4am
+        /// UTC. Day-of-week is numbered 1-7 starting from Sunday, or given by
+        /// name (e.g. "Mon-Fri")
+        #[arg(long, default_value = "0 4 * * *")]
+        cron: String,
+    },
+}
+
+const CONCURRENCY_LIMIT: usize = 4;
+
+/// Fetches (or reuses the cache for) a single already-normalized line name.
+async fn get_one_schedule(
+    client: &reqwest::Client,
+    base_url: &str,
+    name: String,
+    entry: CacheEntry,
+    table: Option<Arc<Vec<ScheduleLink>>>,
+    force_refresh: bool,
+    ttl: Duration,
+) -> Result<PathBuf, String> {
+    if !force_refresh && entry.is_fresh(ttl) {
+        println!("{}: using cached copy at {}", name, entry.pdf_path().display());
+        return Ok(entry.pdf_path());
     }
 
-    let name = args[1..].join(" ");
-    let name = get_normalized_name(&name);
-    println!("Getting schedule for {}", name);
+    let table = table.ok_or_else(|| "schedule listing was not fetched".to_string())?;
+    let href = find_pdf_link_in_table(&table, &name).map_err(|e| e.to_string())?;
+    let base = reqwest::Url::parse(base_url).map_err(|e| e.to_string())?;
+    let url = base.join(&href).map_err(|e| e.to_string())?;
+    println!("{}: downloading {}", name, url);
 
-    let url = match find_pdf_link(&name).await {
-        Ok(href) => {
-            let base_url = reqwest::Url::parse(&URL).unwrap();
-            base_url.join(&href).unwrap()
-        }
-        Err(e) => return Err(e)
+    download_pdf(client, url, &entry).await.map_err(|e| e.to_string())?;
+    entry.touch().map_err(|e| e.to_string())?;
+    Ok(entry.pdf_path())
+}
+
+/// Fetches every requested line concurrently (bounded by `CONCURRENCY_LIMIT`),
+/// sharing a single download of the full-schedules page across all of them,
+/// then reports per-line success/failure and opens everything that succeeded.
+/// When every line is already cached and fresh, the listing isn't fetched at
+/// all, so a fully-cached `get` works offline. Aliases that normalize to the
+/// same line (e.g. "lakeshore west" and "lw") are deduplicated first so two
+/// tasks never race to write the same cache file.
+async fn get_schedules(
+    client: &reqwest::Client,
+    base_url: &str,
+    raw_names: &[String],
+    force_refresh: bool,
+    ttl: Duration,
+) -> Result<(), Box<dyn Error>> {
+    let mut seen = std::collections::HashSet::new();
+    let entries: Vec<(String, CacheEntry)> = raw_names.iter()
+        .map(|raw_name| get_normalized_name(raw_name))
+        .filter(|name| seen.insert(name.clone()))
+        .map(|name| {
+            let entry = CacheEntry::for_line(&name)?;
+            Ok::<_, std::io::Error>((name, entry))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let needs_fetch = force_refresh
+        || entries.iter().any(|(_, entry)| !entry.is_fresh(ttl));
+    let table = if needs_fetch {
+        Some(Arc::new(fetch_schedule_table(client, base_url).await?))
+    } else {
+        None
     };
-    println!("PDF link: {}", url);
 
-    let temp_file = TempFile::get("sched.pdf");
-    println!("Saving to {}", temp_file.filename.display());
-    download_pdf(url, &temp_file).await?;
+    let semaphore = Arc::new(Semaphore::new(CONCURRENCY_LIMIT));
+
+    let tasks: Vec<_> = entries.into_iter().map(|(name, entry)| {
+        let client = client.clone();
+        let base_url = base_url.to_string();
+        let table = table.clone();
+        let semaphore = semaphore.clone();
+        tokio::spawn(async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore not closed");
+            (name.clone(), get_one_schedule(&client, &base_url, name, entry, table, force_refresh, ttl).await)
+        })
+    }).collect();
 
-    open::that(temp_file.filename.as_os_str())?;
+    let mut opened = Vec::new();
+    for joined in futures::future::join_all(tasks).await {
+        match joined {
+            Ok((name, Ok(path))) => {
+                println!("{}: OK", name);
+                opened.push(path);
+            }
+            Ok((name, Err(e))) => eprintln!("{}: FAILED: {}", name, e),
+            Err(join_err) => eprintln!("FAILED: task panicked: {}", join_err),
+        }
+    }
+
+    for path in opened {
+        open::that(path.as_os_str())?;
+    }
 
-    std::thread::sleep(std::time::Duration::new(2, 0));
     Ok(())
 }
+
+async fn list_schedules(client: &reqwest::Client, base_url: &str) -> Result<(), Box<dyn Error>> {
+    let table = fetch_schedule_table(client, base_url).await?;
+    for link in &table {
+        // Like `find_pdf_link_in_table`, don't assume which column holds the
+        // human name vs. the route code - the page's layout isn't guaranteed,
+        // and this couldn't be confirmed against the live markup without
+        // network access. Check both columns and use whichever one resolves
+        // to a known code, falling back to the lowercased key if neither does.
+        let lower_key = link.key.to_ascii_lowercase();
+        let lower_link_text = link.link_text.to_ascii_lowercase();
+        let code = lookup_code(&lower_link_text)
+            .or_else(|| lookup_code(&lower_key))
+            .map(str::to_string)
+            .unwrap_or(lower_key);
+        println!("{:<20} {:<10} -> {}", link.key, link.link_text, code);
+    }
+    Ok(())
+}
+
+/// Re-downloads a single already-normalized line's schedule and logs whether
+/// the PDF bytes actually differ from what was cached before.
+async fn refresh_and_log_change(
+    client: &reqwest::Client,
+    base_url: &str,
+    name: &str,
+    table: &[ScheduleLink],
+) -> Result<(), Box<dyn Error>> {
+    let entry = CacheEntry::for_line(name)?;
+    let previous = fs::read(entry.pdf_path()).ok();
+
+    let href = find_pdf_link_in_table(table, name)?;
+    let base = reqwest::Url::parse(base_url)?;
+    let url = base.join(&href)?;
+    download_pdf(client, url, &entry).await?;
+    entry.touch()?;
+
+    let current = fs::read(entry.pdf_path())?;
+    match previous {
+        Some(prev) if prev == current => println!("{}: unchanged", name),
+        Some(_) => println!("{}: schedule changed", name),
+        None => println!("{}: fetched for the first time", name),
+    }
+    Ok(())
+}
+
+/// Runs forever, waking up on each `cron` fire time to refresh the cache for
+/// every requested line and report whether its schedule actually changed.
+async fn watch_schedules(
+    client: &reqwest::Client,
+    base_url: &str,
+    raw_names: &[String],
+    cron_expr: &str,
+) -> Result<(), Box<dyn Error>> {
+    let names: Vec<String> = raw_names.iter().map(|n| get_normalized_name(n)).collect();
+    // The `cron` crate expects a leading seconds field; ours is always zero.
+    let schedule = Schedule::from_str(&format!("0 {}", cron_expr))?;
+
+    loop {
+        let now = Utc::now();
+        let next = schedule.after(&now).next()
+            .ok_or("cron schedule has no future occurrences")?;
+        let until_next = (next - now).to_std().unwrap_or(Duration::from_secs(0));
+        println!("Next refresh at {} (in {:?})", next, until_next);
+        tokio::time::sleep(until_next).await;
+
+        let table = match fetch_schedule_table(client, base_url).await {
+            Ok(table) => table,
+            Err(e) => {
+                eprintln!("Failed to fetch schedule listing: {}", e);
+                continue;
+            }
+        };
+
+        for name in &names {
+            if let Err(e) = refresh_and_log_change(client, base_url, name, &table).await {
+                eprintln!("{}: FAILED: {}", name, e);
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    let base_url = resolve_base_url(cli.url);
+    let insecure = resolve_insecure(cli.insecure);
+    let ttl = resolve_cache_ttl(cli.ttl);
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(insecure)
+        .build()?;
+
+    match cli.command {
+        Command::Get { names } => get_schedules(&client, &base_url, &names, false, ttl).await,
+        Command::Refresh { names } => get_schedules(&client, &base_url, &names, true, ttl).await,
+        Command::List => list_schedules(&client, &base_url).await,
+        Command::Watch { names, cron } => watch_schedules(&client, &base_url, &names, &cron).await,
+    }
+}